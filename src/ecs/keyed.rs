@@ -0,0 +1,193 @@
+use super::{spawn, RuntimeContext, SpawnContext};
+use crate::{compose::Compose, data::Data, use_context, use_ref, Scope, Signal};
+use bevy_ecs::{entity::Entity, prelude::*};
+use bevy_hierarchy::{despawn_with_children_recursive, BuildChildren};
+use std::{cell::Cell, collections::HashSet, hash::Hash};
+
+/// Create a [`Keyed`] composable that reconciles `items` against the previously composed list by
+/// key, instead of respawning every child on each recomposition.
+///
+/// On recomposition, `items` is diffed against the keys composed last time: a key that was
+/// present before keeps its spawned entity and scope state, a new key spawns a fresh entity, and
+/// a key no longer present has its entity despawned. Remaining children are reordered under the
+/// composition's parent entity to match the new sequence.
+///
+/// Duplicate keys in `items` are deduplicated, keeping the first occurrence.
+///
+/// Note that "keeps its ... scope state" covers the entity itself (retained and reused rather
+/// than despawned and respawned); whether a child's in-scope hook state (e.g. `use_ref` values,
+/// observer guards) survives a pure reorder additionally depends on how the composed `Vec` of
+/// children is itself reconciled, which is outside of `keyed`'s control.
+///
+/// `keyed` assumes it owns every child of its ambient parent entity: each recomposition
+/// unconditionally reinserts its children at index `0` of the parent, so composing it alongside
+/// unrelated sibling content under the same parent (e.g. a static header next to a keyed list of
+/// rows) will repeatedly reorder that sibling content ahead of or behind the keyed children.
+/// Give `keyed` its own dedicated parent (e.g. via [`Spawn::target`](super::Spawn::target)) if
+/// it needs to coexist with other children.
+///
+/// # Examples
+///
+/// ```no_run
+/// use actuate::prelude::*;
+/// use bevy::prelude::*;
+///
+/// #[derive(Data)]
+/// struct Row {
+///     label: String,
+/// }
+///
+/// impl Compose for Row {
+///     fn compose(cx: Scope<Self>) -> impl Compose {
+///         spawn(Text::new(cx.me().label.clone()))
+///     }
+/// }
+///
+/// #[derive(Data)]
+/// struct List {
+///     rows: Vec<(u32, String)>,
+/// }
+///
+/// impl Compose for List {
+///     fn compose(cx: Scope<Self>) -> impl Compose {
+///         keyed(
+///             cx.me()
+///                 .rows
+///                 .iter()
+///                 .map(|(id, label)| (*id, Row { label: label.clone() })),
+///         )
+///     }
+/// }
+/// ```
+pub fn keyed<K, C>(items: impl IntoIterator<Item = (K, C)>) -> Keyed<K, C>
+where
+    K: Clone + Eq + Hash,
+    C: Compose,
+{
+    let mut seen = HashSet::new();
+    let items = items
+        .into_iter()
+        .filter(|(key, _)| seen.insert(key.clone()))
+        .collect();
+
+    Keyed { items }
+}
+
+/// Composable that reconciles a keyed list of children against the ECS hierarchy.
+///
+/// See [`keyed`] for more information.
+#[must_use = "Composables do nothing unless composed or returned from other composables."]
+pub struct Keyed<K, C> {
+    items: Vec<(K, C)>,
+}
+
+unsafe impl<K, C: Data> Data for Keyed<K, C> {}
+
+impl<K, C> Compose for Keyed<K, C>
+where
+    K: Clone + Eq + Hash + 'static,
+    C: Compose,
+{
+    fn compose(cx: Scope<Self>) -> impl Compose {
+        let spawn_cx = use_context::<SpawnContext>(&cx);
+
+        let prev = use_ref(&cx, || Cell::new(Vec::<(K, Entity)>::new()));
+        let prev_keys = prev.take();
+
+        let world = unsafe { RuntimeContext::current().world_mut() };
+
+        let next: Vec<(K, Entity)> = reconcile(
+            &prev_keys,
+            cx.me().items.iter().map(|(key, _)| key.clone()),
+            || world.spawn_empty().id(),
+        );
+
+        for (prev_key, entity) in &prev_keys {
+            if !next.iter().any(|(key, _)| key == prev_key) {
+                // Despawn recursively: the item's own composable may have spawned children
+                // underneath this entity, which would otherwise be orphaned.
+                //
+                // This runs before the framework drops that key's own `Spawn` scope (and its
+                // `on_remove` teardown, if any) against an entity that's already gone; `Spawn`'s
+                // `on_remove` tolerates that via `run_on_remove`.
+                despawn_with_children_recursive(world, *entity, false);
+            }
+        }
+
+        if let Ok(spawn_cx) = spawn_cx {
+            let children: Vec<_> = next.iter().map(|(_, entity)| *entity).collect();
+            world
+                .entity_mut(spawn_cx.parent_entity)
+                .insert_children(0, &children);
+        }
+
+        prev.set(next.clone());
+
+        next.into_iter()
+            .enumerate()
+            .map(|(index, (_, entity))| {
+                let content =
+                    unsafe { Signal::map_unchecked(cx.me(), move |me| &me.items[index].1) };
+                spawn(()).target(entity).content(content)
+            })
+            .collect::<Vec<_>>()
+    }
+}
+
+/// Diff `keys` (in their new order) against `prev`, reusing each key's previous entity where one
+/// exists and allocating a fresh one via `spawn_entity` otherwise.
+fn reconcile<K: Clone + Eq>(
+    prev: &[(K, Entity)],
+    keys: impl Iterator<Item = K>,
+    mut spawn_entity: impl FnMut() -> Entity,
+) -> Vec<(K, Entity)> {
+    keys.map(|key| {
+        let entity = prev
+            .iter()
+            .find(|(prev_key, _)| *prev_key == key)
+            .map(|(_, entity)| *entity)
+            .unwrap_or_else(&mut spawn_entity);
+        (key, entity)
+    })
+    .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::reconcile;
+    use bevy_ecs::entity::Entity;
+
+    fn entity(index: u32) -> Entity {
+        Entity::from_raw(index)
+    }
+
+    #[test]
+    fn spawns_fresh_entities_for_new_keys() {
+        let prev = Vec::new();
+        let mut next_id = 0;
+        let next = reconcile(&prev, [1, 2].into_iter(), || {
+            next_id += 1;
+            entity(next_id)
+        });
+
+        assert_eq!(next, [(1, entity(1)), (2, entity(2))]);
+    }
+
+    #[test]
+    fn reuses_entities_for_retained_keys_and_drops_removed_ones() {
+        let prev = vec![(1, entity(10)), (2, entity(20)), (3, entity(30))];
+        let next = reconcile(&prev, [1, 3].into_iter(), || unreachable!("no new keys"));
+
+        // Key `2`'s entity is absent from `next`; the caller is responsible for despawning it.
+        assert_eq!(next, [(1, entity(10)), (3, entity(30))]);
+    }
+
+    #[test]
+    fn reordering_keeps_each_key_paired_with_its_own_entity() {
+        let prev = vec![(1, entity(10)), (2, entity(20))];
+        let next = reconcile(&prev, [2, 1].into_iter(), || unreachable!("no new keys"));
+
+        // The entities travel with their keys, not with their position in the list.
+        assert_eq!(next, [(2, entity(20)), (1, entity(10))]);
+    }
+}
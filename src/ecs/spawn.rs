@@ -2,11 +2,19 @@ use super::{use_bundle_inner, RuntimeContext, SpawnContext, SystemParamFunction}
 use crate::{
     compose::Compose, data::Data, use_context, use_drop, use_provider, use_ref, Scope, Signal,
 };
-use bevy_ecs::{entity::Entity, prelude::*, world::World};
-use bevy_hierarchy::BuildChildren;
+use bevy_ecs::{
+    component::ComponentId,
+    entity::Entity,
+    observer::{ObserverDescriptor, ObserverState, ObserverTrigger},
+    prelude::*,
+    world::{DeferredWorld, World},
+};
+use bevy_hierarchy::{BuildChildren, Parent};
+use bevy_ptr::PtrMut;
 use std::{
     cell::Cell,
     mem,
+    ops::{Deref, DerefMut},
     sync::{Arc, Mutex},
 };
 
@@ -51,6 +59,7 @@ where
         observer_guard: Arc::new(Mutex::new(true)),
         on_add: Cell::new(None),
         on_insert: Vec::new(),
+        on_remove: Cell::new(None),
     }
 }
 
@@ -62,6 +71,11 @@ type OnAddFn<'a> = Box<dyn FnOnce(EntityWorldMut) + 'a>;
 
 type OnInsertFn<'a> = Box<dyn Fn(EntityWorldMut) + 'a>;
 
+// Unlike `OnAddFn`/`OnInsertFn`, this is read back out of hook storage and invoked later, on
+// `use_drop`, possibly many recompositions after the `Spawn` value that registered it is gone —
+// so it must not borrow anything scoped to that value's `'a`.
+type OnRemoveFn = Box<dyn FnOnce(EntityWorldMut)>;
+
 /// Composable to spawn an entity.
 ///
 /// See [`spawn`] for more information.
@@ -73,6 +87,7 @@ pub struct Spawn<'a, C = ()> {
     observer_fns: Vec<ObserverFn<'a>>,
     on_add: Cell<Option<OnAddFn<'a>>>,
     on_insert: Vec<OnInsertFn<'a>>,
+    on_remove: Cell<Option<OnRemoveFn>>,
     observer_guard: Arc<Mutex<bool>>,
 }
 
@@ -95,6 +110,7 @@ impl<'a, C> Spawn<'a, C> {
             on_add: self.on_add,
             observer_guard: Arc::new(Mutex::new(false)),
             on_insert: self.on_insert,
+            on_remove: self.on_remove,
         }
     }
 
@@ -113,6 +129,23 @@ impl<'a, C> Spawn<'a, C> {
         self
     }
 
+    /// Set a function to be called with mutable access to this entity when its scope is
+    /// dropped, just before the entity is despawned.
+    ///
+    /// This is the teardown counterpart to [`Spawn::on_spawn`]. `f` is `'static` because it is
+    /// held in hook storage and invoked later, possibly after this `Spawn` value's own borrows
+    /// have ended.
+    ///
+    /// If the entity no longer exists when the scope is dropped, `f` is not called. This is the
+    /// case for an item removed from a `keyed` list, whose entity `keyed` despawns first.
+    pub fn on_remove<F>(self, f: F) -> Self
+    where
+        F: FnOnce(EntityWorldMut) + 'static,
+    {
+        self.on_remove.set(Some(Box::new(f)));
+        self
+    }
+
     /// Add an observer to the spawned entity.
     pub fn observe<F, E, B, Marker>(mut self, observer: F) -> Self
     where
@@ -154,6 +187,188 @@ impl<'a, C> Spawn<'a, C> {
         }));
         self
     }
+
+    /// Add an observer to the spawned entity that re-triggers its event on the parent
+    /// composable's entity, bubbling up the composition hierarchy.
+    ///
+    /// The handler is passed a [`PropagatedTrigger`] instead of a plain [`Trigger`]; call
+    /// [`PropagatedTrigger::stop_propagation`] to prevent the event from reaching ancestors.
+    ///
+    /// Propagation is implemented by each handler re-triggering the event on its own parent, not
+    /// by Bevy-level event traversal, so there is no relay at an entity unless *that* entity also
+    /// has its own `observe_propagated` handler for this exact event type. An intermediate entity
+    /// with no handler (or only a plain [`Spawn::observe`]) does not forward the event, and
+    /// bubbling silently stops there — every entity the event should pass through needs to opt in.
+    pub fn observe_propagated<F, E, B, Marker>(mut self, observer: F) -> Self
+    where
+        F: SystemParamFunction<Marker, In = PropagatedTrigger<'static, E, B>, Out = ()>
+            + Send
+            + Sync
+            + 'a,
+        E: Event + Clone,
+        B: Bundle,
+    {
+        let cell = Cell::new(Some(observer));
+        let guard = self.observer_guard.clone();
+
+        self.observer_fns.push(Box::new(move |entity| {
+            let mut observer = cell.take().unwrap();
+            let guard = guard.clone();
+
+            type PropagatedObserveFn<'a, F, E, B, Marker> = Box<
+                dyn FnMut(
+                        Trigger<'_, E, B>,
+                        ParamSet<'_, '_, (<F as SystemParamFunction<Marker>>::Param,)>,
+                        Local<'_, Cell<bool>>,
+                        Query<'_, '_, &Parent>,
+                        Commands,
+                    ) + Send
+                    + Sync
+                    + 'a,
+            >;
+
+            let f: PropagatedObserveFn<'a, F, E, B, Marker> = Box::new(
+                move |trigger, mut params, mut should_propagate, parents, mut commands| {
+                    let guard = guard.lock().unwrap();
+                    if !*guard {
+                        panic!("Actuate observer called after its scope was dropped.")
+                    }
+
+                    let target = trigger.entity();
+                    let event = trigger.event().clone();
+                    should_propagate.set(true);
+
+                    // Safety: The event and propagation flag will be accessed under a
+                    // shortened lifetime.
+                    let trigger: Trigger<'static, E, B> = unsafe { mem::transmute(trigger) };
+                    let propagate: &'static Cell<bool> =
+                        unsafe { mem::transmute(&*should_propagate) };
+
+                    observer.run(PropagatedTrigger { trigger, propagate }, params.p0());
+
+                    if propagate.get() {
+                        if let Ok(parent) = parents.get(target) {
+                            commands.trigger_targets(event, parent.get());
+                        }
+                    }
+                },
+            );
+
+            // Safety: The observer will be disabled after this scope is dropped.
+            let f: PropagatedObserveFn<'static, F, E, B, Marker> = unsafe { mem::transmute(f) };
+
+            entity.observe(f);
+        }));
+        self
+    }
+
+    /// Add an observer for an event whose type is only known at runtime, identified by its
+    /// [`ComponentId`].
+    ///
+    /// `callback` receives the trigger's type-erased payload and a restricted [`DeferredWorld`]
+    /// handle instead of a typed [`Trigger`]. It's `'static`, as it ends up stored in a
+    /// `DynObserverCallback` component on its dedicated observer entity.
+    /// As with the statically typed observers, it panics if invoked after this scope is dropped.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use actuate::prelude::*;
+    /// use bevy::prelude::*;
+    ///
+    /// fn bind(cx: Scope<impl Compose>, event_id: ComponentId) -> impl Compose {
+    ///     spawn(SpatialBundle::default()).observe_dynamic(event_id, |_world, _trigger, _payload| {
+    ///         // Handle the runtime-typed event.
+    ///     })
+    /// }
+    /// ```
+    pub fn observe_dynamic<F>(mut self, event_id: ComponentId, callback: F) -> Self
+    where
+        F: Fn(DeferredWorld, ObserverTrigger, PtrMut) + Send + Sync + 'static,
+    {
+        let cell = Cell::new(Some(callback));
+        let guard = self.observer_guard.clone();
+
+        self.observer_fns.push(Box::new(move |entity| {
+            let callback = cell.take().unwrap();
+            let guard = guard.clone();
+
+            let callback: Arc<dyn Fn(DeferredWorld, ObserverTrigger, PtrMut) + Send + Sync> =
+                Arc::new(move |world, trigger, payload| {
+                    let guard = guard.lock().unwrap();
+                    if !*guard {
+                        panic!("Actuate observer called after its scope was dropped.")
+                    }
+
+                    callback(world, trigger, payload);
+                });
+
+            // The observer's raw `runner` is a plain, non-capturing `fn`; the actual callback
+            // is looked up from a `DynObserverCallback` component on the observer entity so the
+            // runner doesn't need to capture anything itself.
+            let descriptor = ObserverDescriptor::default()
+                .with_entities([entity.id()])
+                .with_events([event_id]);
+
+            entity.world_scope(|world| {
+                world.spawn((
+                    ObserverState {
+                        descriptor,
+                        runner: run_dyn_observer,
+                        ..Default::default()
+                    },
+                    DynObserverCallback(callback),
+                ));
+            });
+        }));
+        self
+    }
+}
+
+/// The type-erased callback registered by [`Spawn::observe_dynamic`], stored as a component on
+/// its dedicated observer entity so that [`run_dyn_observer`] can look it up without capturing
+/// any state of its own.
+#[derive(Component)]
+struct DynObserverCallback(Arc<dyn Fn(DeferredWorld, ObserverTrigger, PtrMut) + Send + Sync>);
+
+fn run_dyn_observer(mut world: DeferredWorld, trigger: ObserverTrigger, payload: PtrMut) {
+    let callback = world
+        .get::<DynObserverCallback>(trigger.observer())
+        .map(|callback| callback.0.clone());
+
+    if let Some(callback) = callback {
+        callback(world, trigger, payload);
+    }
+}
+
+/// A [`Trigger`] observed through [`Spawn::observe_propagated`].
+///
+/// Dereferences to the underlying [`Trigger`]. Call [`PropagatedTrigger::stop_propagation`] from
+/// within the handler to prevent the event from bubbling up to the parent composable.
+pub struct PropagatedTrigger<'w, E, B: Bundle = ()> {
+    trigger: Trigger<'w, E, B>,
+    propagate: &'w Cell<bool>,
+}
+
+impl<E, B: Bundle> PropagatedTrigger<'_, E, B> {
+    /// Stop this event from propagating to the parent composable.
+    pub fn stop_propagation(&self) {
+        self.propagate.set(false);
+    }
+}
+
+impl<'w, E, B: Bundle> Deref for PropagatedTrigger<'w, E, B> {
+    type Target = Trigger<'w, E, B>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.trigger
+    }
+}
+
+impl<E, B: Bundle> DerefMut for PropagatedTrigger<'_, E, B> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.trigger
+    }
 }
 
 unsafe impl<C: Data> Data for Spawn<'_, C> {}
@@ -207,6 +422,116 @@ impl<C: Compose> Compose for Spawn<'_, C> {
             *guard.lock().unwrap() = false;
         });
 
+        let on_remove = use_ref(&cx, || Cell::new(None));
+        if let Some(f) = cx.me().on_remove.take() {
+            on_remove.set(Some(f));
+        }
+        use_drop(&cx, move || {
+            if let Some(f) = on_remove.take() {
+                let world = unsafe { RuntimeContext::current().world_mut() };
+                run_on_remove(world, entity, f);
+            }
+        });
+
         unsafe { Signal::map_unchecked(cx.me(), |me| &me.content) }
     }
 }
+
+/// Run an `on_remove` callback against `entity`, if it still exists.
+///
+/// Teardown is the one point where an entity isn't guaranteed to still be alive: it may have
+/// already been despawned by something else (another `on_remove`, a `keyed` list dropping the
+/// item, an ancestor despawning recursively), so unlike `on_add`/`on_insert` this can't assume
+/// `world.entity_mut(entity)` will succeed.
+fn run_on_remove(world: &mut World, entity: Entity, f: OnRemoveFn) {
+    if let Some(entity_mut) = world.get_entity_mut(entity) {
+        f(entity_mut);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::run_on_remove;
+    use bevy_ecs::world::World;
+    use std::{cell::Cell, rc::Rc};
+
+    #[test]
+    fn on_remove_runs_against_a_live_entity() {
+        let mut world = World::new();
+        let entity = world.spawn_empty().id();
+
+        let ran = Rc::new(Cell::new(false));
+        let flag = ran.clone();
+        run_on_remove(&mut world, entity, Box::new(move |_| flag.set(true)));
+
+        assert!(ran.get());
+    }
+
+    #[test]
+    fn on_remove_tolerates_an_already_despawned_entity() {
+        let mut world = World::new();
+        let entity = world.spawn_empty().id();
+        world.despawn(entity);
+
+        let ran = Rc::new(Cell::new(false));
+        let flag = ran.clone();
+        run_on_remove(&mut world, entity, Box::new(move |_| flag.set(true)));
+
+        assert!(!ran.get());
+    }
+}
+
+#[cfg(test)]
+mod propagation_tests {
+    use super::{spawn, PropagatedTrigger};
+    use bevy_ecs::prelude::*;
+    use bevy_hierarchy::BuildChildren;
+
+    #[derive(Event, Clone)]
+    struct Bump;
+
+    #[derive(Resource, Default)]
+    struct Hits {
+        grandparent: u32,
+    }
+
+    #[test]
+    fn propagation_stops_at_an_intermediate_entity_with_no_handler() {
+        let mut world = World::new();
+        world.init_resource::<Hits>();
+
+        let grandparent = world.spawn_empty().id();
+        let middle = world.spawn_empty().id();
+        let child = world.spawn_empty().id();
+
+        world.entity_mut(grandparent).add_child(middle);
+        world.entity_mut(middle).add_child(child);
+
+        // The grandparent has a handler; `middle`, sitting directly in between, has none.
+        let grandparent_spawn = spawn(()).observe_propagated(
+            |_trigger: PropagatedTrigger<Bump>, mut hits: ResMut<Hits>| {
+                hits.grandparent += 1;
+            },
+        );
+        let mut grandparent_mut = world.entity_mut(grandparent);
+        for f in &grandparent_spawn.observer_fns {
+            f(&mut grandparent_mut);
+        }
+
+        // The child also has a handler, so firing `Bump` there re-triggers it on `middle`.
+        let child_spawn = spawn(()).observe_propagated(
+            |_trigger: PropagatedTrigger<Bump>, _hits: ResMut<Hits>| {},
+        );
+        let mut child_mut = world.entity_mut(child);
+        for f in &child_spawn.observer_fns {
+            f(&mut child_mut);
+        }
+
+        world.trigger_targets(Bump, child);
+        world.flush();
+
+        // `middle` has no `observe_propagated` handler of its own, so nothing there re-triggers
+        // `Bump` on `grandparent` — propagation silently stops at `middle`, matching the doc.
+        assert_eq!(world.resource::<Hits>().grandparent, 0);
+    }
+}
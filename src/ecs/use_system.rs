@@ -0,0 +1,90 @@
+use super::RuntimeContext;
+use crate::{use_drop, use_ref, Scope};
+use bevy_ecs::{
+    system::{IntoSystem, SystemId},
+    world::World,
+};
+use std::{
+    cell::Cell,
+    sync::{Arc, Mutex},
+};
+
+/// Register `system` once and return a [`SystemHandle`] that runs it on demand.
+///
+/// The system is registered with [`World::register_system`] the first time this hook runs, and
+/// unregistered in a `use_drop` when the enclosing scope ends. This gives a composable access to
+/// full [`SystemParam`](bevy_ecs::system::SystemParam) injection (`Query`, `Res`, `Commands`)
+/// for effects triggered from composition.
+///
+/// # Examples
+///
+/// ```no_run
+/// use actuate::prelude::*;
+/// use bevy::prelude::*;
+///
+/// #[derive(Data)]
+/// struct LogOnSpawn;
+///
+/// impl Compose for LogOnSpawn {
+///     fn compose(cx: Scope<Self>) -> impl Compose {
+///         let log = use_system(&cx, |query: Query<Entity>| {
+///             info!("entity count: {}", query.iter().count());
+///         });
+///
+///         // `world_scope` hands back a fresh `&mut World`, so calling `run` here doesn't
+///         // alias the `EntityWorldMut` this callback is already holding.
+///         spawn(()).on_spawn(move |mut entity| entity.world_scope(|world| log.run(world)))
+///     }
+/// }
+/// ```
+pub fn use_system<C, Marker>(
+    cx: &Scope<C>,
+    system: impl IntoSystem<(), (), Marker> + 'static,
+) -> SystemHandle {
+    let system_id = use_ref(cx, || {
+        let world = unsafe { RuntimeContext::current().world_mut() };
+        Cell::new(world.register_system(system))
+    });
+    let guard = use_ref(cx, || Arc::new(Mutex::new(true)));
+
+    use_drop(cx, move || {
+        *guard.lock().unwrap() = false;
+
+        let world = unsafe { RuntimeContext::current().world_mut() };
+        let _ = world.remove_system(system_id.get());
+    });
+
+    SystemHandle {
+        system_id: system_id.get(),
+        guard: guard.clone(),
+    }
+}
+
+/// A handle to a system registered by [`use_system`].
+#[derive(Clone)]
+pub struct SystemHandle {
+    system_id: SystemId,
+    guard: Arc<Mutex<bool>>,
+}
+
+impl SystemHandle {
+    /// Run this system against `world`.
+    ///
+    /// Unlike `use_system` and [`use_drop`], this takes `world` explicitly rather than reaching
+    /// for [`RuntimeContext::current`] itself: callbacks such as `Spawn::on_insert` already run
+    /// with an exclusive borrow of the world live on the stack (via `EntityWorldMut`), and a
+    /// second, implicit `world_mut()` call from inside them would alias it. Call this with a
+    /// `&mut World` you already hold, or defer it through `Commands::run_system` if you only
+    /// have a `Commands`.
+    ///
+    /// Panics if the scope that registered this system has already been dropped, matching the
+    /// convention `Spawn::observe`/`observe_propagated`/`observe_dynamic` use for the same
+    /// use-after-drop case, rather than silently no-oping.
+    pub fn run(&self, world: &mut World) {
+        if !*self.guard.lock().unwrap() {
+            panic!("Actuate system called after its scope was dropped.")
+        }
+
+        let _ = world.run_system(self.system_id);
+    }
+}